@@ -1,6 +1,7 @@
-use bevy::input::mouse::MouseMotion;
+use bevy::input::mouse::{MouseMotion, MouseWheel};
 use bevy::prelude::*;
 use bevy::window::{CursorGrabMode, PrimaryWindow};
+use bevy::winit::cursor::{CursorIcon, CustomCursor};
 
 pub mod prelude {
     pub use crate::*;
@@ -11,7 +12,14 @@ pub mod prelude {
 pub struct MovementSettings {
     pub mouse_sensitivity: f32,
     pub keyboard_sensitivity: f32,
-    pub move_speed: f32,
+    /// Top speed while walking, in units/sec
+    pub walk_speed: f32,
+    /// Top speed while the sprint key is held, in units/sec
+    pub run_speed: f32,
+    /// How quickly velocity approaches the target speed, in units/sec^2
+    pub acceleration: f32,
+    /// How quickly velocity decays toward zero once no movement key is held
+    pub friction: f32,
 }
 
 impl Default for MovementSettings {
@@ -19,7 +27,10 @@ impl Default for MovementSettings {
         Self {
             mouse_sensitivity: 0.00012,
             keyboard_sensitivity: 0.05,
-            move_speed: 12.,
+            walk_speed: 12.,
+            run_speed: 24.,
+            acceleration: 60.,
+            friction: 10.,
         }
     }
 }
@@ -38,6 +49,18 @@ pub struct KeyBindings {
     pub look_right: KeyCode,
     pub look_up: KeyCode,
     pub look_down: KeyCode,
+    /// Held to move at `MovementSettings::run_speed` instead of `walk_speed`
+    pub sprint: KeyCode,
+    /// Cycles `CameraMode` between `FreeFly` and `Orbit`
+    pub toggle_camera_mode: KeyCode,
+    /// Cycles which field the mouse wheel adjusts, see [`ScrollTarget`]
+    pub cycle_scroll_target: KeyCode,
+    /// Held to ease the fov toward `ZoomSettings::zoomed_fov`
+    pub zoom: KeyCode,
+    /// Cycles through scene cameras discovered by [`discover_scene_cameras`], wrapping back to
+    /// the `FlyCam`
+    #[cfg(feature = "scene-camera-cycling")]
+    pub cycle_scene_camera: KeyCode,
 }
 
 impl Default for KeyBindings {
@@ -48,26 +71,125 @@ impl Default for KeyBindings {
             move_left: KeyCode::KeyA,
             move_right: KeyCode::KeyD,
             move_ascend: KeyCode::Space,
-            move_descend: KeyCode::ShiftLeft,
+            move_descend: KeyCode::ControlLeft,
             toggle_grab_cursor: KeyCode::Escape,
             look_left: KeyCode::ArrowLeft,
             look_right: KeyCode::ArrowRight,
             look_up: KeyCode::ArrowUp,
-            look_down: KeyCode::ArrowDown
+            look_down: KeyCode::ArrowDown,
+            sprint: KeyCode::ShiftLeft,
+            toggle_camera_mode: KeyCode::KeyC,
+            cycle_scroll_target: KeyCode::KeyV,
+            zoom: KeyCode::KeyZ,
+            #[cfg(feature = "scene-camera-cycling")]
+            cycle_scene_camera: KeyCode::Tab,
         }
     }
 }
 
+/// FOV zoom configuration, see [`zoom_camera`]
+#[derive(Resource)]
+pub struct ZoomSettings {
+    /// Resting field of view, in degrees
+    pub default_fov: f32,
+    /// Field of view while `KeyBindings::zoom` is held, in degrees
+    pub zoomed_fov: f32,
+    /// How quickly fov eases between `default_fov` and `zoomed_fov`
+    pub zoom_speed: f32,
+    /// Scale `MovementSettings::mouse_sensitivity` by the ratio of current-to-default fov while
+    /// zoomed, so looking around feels consistent at any zoom level
+    pub scale_sensitivity: bool,
+}
+
+impl Default for ZoomSettings {
+    fn default() -> Self {
+        Self {
+            default_fov: 45.,
+            zoomed_fov: 15.,
+            zoom_speed: 8.,
+            scale_sensitivity: true,
+        }
+    }
+}
+
+/// Ordered list of non-`FlyCam` cameras discovered in the scene (e.g. spawned by a loaded glTF),
+/// and which one, if any, is currently rendered instead of the `FlyCam`. Populated by
+/// [`discover_scene_cameras`] and driven by [`cycle_scene_cameras`].
+#[cfg(feature = "scene-camera-cycling")]
+#[derive(Resource, Default)]
+pub struct SceneCameras {
+    pub cameras: Vec<Entity>,
+    pub active: Option<usize>,
+}
+
+/// Which setting the mouse wheel currently adjusts, see [`scroll_adjust_settings`]
+#[derive(Resource, Clone, Copy, PartialEq, Eq)]
+pub enum ScrollTarget {
+    MovementSpeed,
+    Sensitivity,
+    Zoom,
+}
+
+impl Default for ScrollTarget {
+    fn default() -> Self {
+        ScrollTarget::MovementSpeed
+    }
+}
+
+/// Selects how `player_move`/`player_look` drive the `FlyCam`
+#[derive(Resource, Clone, Copy, PartialEq)]
+pub enum CameraMode {
+    /// Regular WASD + mouse-look flying, the only mode the plugin used to support
+    FreeFly,
+    /// Orbits `target` at a fixed `distance`, steered by the same mouse/keyboard look input
+    Orbit { target: Vec3, distance: f32 },
+    /// Tracks `entity`'s `Transform` with a fixed positional `offset`
+    Follow { entity: Entity, offset: Vec3 },
+}
+
+impl Default for CameraMode {
+    fn default() -> Self {
+        CameraMode::FreeFly
+    }
+}
+
 /// Used in queries when you want flycams and not other cameras
 /// A marker component used in queries when you want flycams and not other cameras
 #[derive(Component)]
 pub struct FlyCam;
 
+/// Persistent per-camera velocity driving inertial movement in [`player_move`]
+#[derive(Component, Default)]
+pub struct Velocity(pub Vec3);
+
+/// Configures how the mouse cursor is grabbed, see [`cursor_grab`]
+#[derive(Resource)]
+pub struct CursorGrabSettings {
+    /// `CursorGrabMode` applied while the cursor is grabbed. `Confined` by default; platforms
+    /// that need unbounded mouse delta (most do, for looking around) should use `Locked` instead
+    pub grab_mode: CursorGrabMode,
+    /// If set, the cursor is grabbed only while this button is held and released on button-up,
+    /// instead of toggling on `KeyBindings::toggle_grab_cursor`
+    pub hold_to_look: Option<MouseButton>,
+    /// Custom cursor image shown while the cursor is ungrabbed
+    pub custom_cursor: Option<Handle<Image>>,
+}
+
+impl Default for CursorGrabSettings {
+    fn default() -> Self {
+        Self {
+            grab_mode: CursorGrabMode::Confined,
+            hold_to_look: None,
+            custom_cursor: None,
+        }
+    }
+}
+
 /// Grabs/ungrabs mouse cursor
-fn toggle_grab_cursor(window: &mut Window) {
+fn toggle_grab_cursor(window: &mut Window, settings: &CursorGrabSettings) {
     match window.cursor_options.grab_mode {
         CursorGrabMode::None => {
-            window.cursor_options.grab_mode = CursorGrabMode::Confined;
+            window.cursor_options.grab_mode = settings.grab_mode;
             window.cursor_options.visible = false;
         }
         _ => {
@@ -79,9 +201,12 @@ fn toggle_grab_cursor(window: &mut Window) {
 
 #[cfg(feature = "initial-grab-control")]
 /// Grabs the cursor when game first starts
-fn initial_grab_cursor(mut primary_window: Query<&mut Window, With<PrimaryWindow>>) {
+fn initial_grab_cursor(
+    mut primary_window: Query<&mut Window, With<PrimaryWindow>>,
+    settings: Res<CursorGrabSettings>,
+) {
     if let Ok(mut window) = primary_window.get_single_mut() {
-        toggle_grab_cursor(&mut window);
+        toggle_grab_cursor(&mut window, &settings);
     } else {
         warn!("Primary window not found for `initial_grab_cursor`!");
     }
@@ -92,22 +217,42 @@ fn setup_player(mut commands: Commands) {
     commands.spawn((
         Camera3d::default(),
         FlyCam,
+        Velocity::default(),
         Transform::from_xyz(-2.0, 5.0, 5.0).looking_at(Vec3::ZERO, Vec3::Y),
     ));
 }
 
-/// Handles keyboard input and movement
+/// Makes sure every `FlyCam` has a `Velocity` to accelerate/decelerate, even ones spawned
+/// by the user rather than [`setup_player`] (as with [`NoCameraPlayerPlugin`])
+fn initial_velocity_on_flycam_spawn(
+    mut commands: Commands,
+    query_added: Query<Entity, (Added<FlyCam>, Without<Velocity>)>,
+) {
+    for entity in query_added.iter() {
+        commands.entity(entity).insert(Velocity::default());
+    }
+}
+
+/// Handles keyboard input and movement. Only applies in [`CameraMode::FreeFly`]; `Orbit` is
+/// positioned by [`player_look`] at the distance set in [`adjust_orbit_distance`], and `Follow`
+/// by [`apply_camera_follow`]
 fn player_move(
     keys: Res<ButtonInput<KeyCode>>,
     time: Res<Time>,
     primary_window: Query<&Window, With<PrimaryWindow>>,
     settings: Res<MovementSettings>,
     key_bindings: Res<KeyBindings>,
-    mut query: Query<(&FlyCam, &mut Transform)>, //    mut query: Query<&mut Transform, With<FlyCam>>,
+    mode: Res<CameraMode>,
+    mut query: Query<(&FlyCam, &mut Transform, &mut Velocity)>,
 ) {
+    if *mode != CameraMode::FreeFly {
+        return;
+    }
+
     if let Ok(window) = primary_window.single() {
-        for (_camera, mut transform) in query.iter_mut() {
-            let mut velocity = Vec3::ZERO;
+        for (_camera, mut transform, mut velocity) in query.iter_mut() {
+            let mut direction = Vec3::ZERO;
+            let mut sprinting = false;
             let local_z = transform.local_z();
             let forward = -Vec3::new(local_z.x, 0., local_z.z);
             let right = Vec3::new(local_z.z, 0., -local_z.x);
@@ -118,32 +263,57 @@ fn player_move(
                     _ => {
                         let key = *key;
                         if key == key_bindings.move_forward {
-                            velocity += forward;
+                            direction += forward;
                         } else if key == key_bindings.move_backward {
-                            velocity -= forward;
+                            direction -= forward;
                         } else if key == key_bindings.move_left {
-                            velocity -= right;
+                            direction -= right;
                         } else if key == key_bindings.move_right {
-                            velocity += right;
+                            direction += right;
                         } else if key == key_bindings.move_ascend {
-                            velocity += Vec3::Y;
+                            direction += Vec3::Y;
                         } else if key == key_bindings.move_descend {
-                            velocity -= Vec3::Y;
+                            direction -= Vec3::Y;
+                        } else if key == key_bindings.sprint {
+                            sprinting = true;
                         }
                     }
                 }
             }
 
-            velocity = velocity.normalize_or_zero();
+            let direction = direction.normalize_or_zero();
+            let dt = time.delta_secs();
+            let target_speed = if sprinting {
+                settings.run_speed
+            } else {
+                settings.walk_speed
+            };
+
+            if direction != Vec3::ZERO {
+                let prev_speed = velocity.0.length();
+                velocity.0 += direction * settings.acceleration * dt;
 
-            transform.translation += velocity * time.delta_secs() * settings.move_speed
+                let speed = velocity.0.length();
+                if speed > target_speed {
+                    // Ease speed back down instead of hard-clamping it, so releasing sprint (or
+                    // any other drop in target speed) decelerates smoothly rather than snapping
+                    // to the new speed in a single frame.
+                    let capped_speed = (prev_speed - settings.acceleration * dt).max(target_speed);
+                    velocity.0 *= capped_speed / speed;
+                }
+            } else {
+                velocity.0 *= 1.0 / (1.0 + settings.friction * dt);
+            }
+
+            transform.translation += velocity.0 * dt;
         }
     } else {
         warn!("Primary window not found for `player_move`!");
     }
 }
 
-/// Handles looking around if cursor is locked
+/// Handles looking around if cursor is locked. In `FreeFly` this rotates the camera in place; in
+/// `Orbit` the same yaw/pitch instead swings the camera around its target at a fixed distance
 fn player_look(
     keys: Res<ButtonInput<KeyCode>>,
     key_bindings: Res<KeyBindings>,
@@ -151,6 +321,7 @@ fn player_look(
     settings: Res<MovementSettings>,
     primary_window: Query<&Window, With<PrimaryWindow>>,
     mut state: EventReader<MouseMotion>,
+    mode: Res<CameraMode>,
     mut query: Query<&mut Transform, With<FlyCam>>,
 ) {
     if let Ok(window) = primary_window.single() {
@@ -187,40 +358,327 @@ fn player_look(
             pitch = pitch.clamp(-1.54, 1.54);
 
             // Order is important to prevent unintended roll
-            transform.rotation =
+            let rotation =
                 Quat::from_axis_angle(Vec3::Y, yaw) * Quat::from_axis_angle(Vec3::X, pitch);
+
+            match *mode {
+                CameraMode::FreeFly => {
+                    transform.rotation = rotation;
+                }
+                CameraMode::Orbit { target, distance } => {
+                    transform.translation = target + rotation * Vec3::new(0., 0., distance);
+                    transform.look_at(target, Vec3::Y);
+                }
+                // `apply_camera_follow` owns the `FlyCam`'s transform entirely while following;
+                // yaw/pitch from look input isn't tracked here to avoid fighting it.
+                CameraMode::Follow { .. } => {}
+            }
         }
     } else {
         warn!("Primary window not found for `player_look`!");
     }
 }
 
+/// Cycles [`CameraMode`] between `FreeFly` and `Orbit`. `Follow` isn't part of the cycle since it
+/// needs a target `Entity` the plugin has no way to pick on its own - set it on the `CameraMode`
+/// resource directly to use it.
+fn cycle_camera_mode(
+    keys: Res<ButtonInput<KeyCode>>,
+    key_bindings: Res<KeyBindings>,
+    mut mode: ResMut<CameraMode>,
+) {
+    if keys.just_pressed(key_bindings.toggle_camera_mode) {
+        *mode = match *mode {
+            CameraMode::FreeFly => CameraMode::Orbit {
+                target: Vec3::ZERO,
+                distance: 10.,
+            },
+            CameraMode::Orbit { .. } | CameraMode::Follow { .. } => CameraMode::FreeFly,
+        };
+    }
+}
+
+/// In `Orbit` mode, the ascend/descend keys and the mouse wheel move the camera closer to or
+/// farther from its target instead of moving it vertically
+fn adjust_orbit_distance(
+    keys: Res<ButtonInput<KeyCode>>,
+    key_bindings: Res<KeyBindings>,
+    time: Res<Time>,
+    mut scroll_evr: EventReader<MouseWheel>,
+    mut mode: ResMut<CameraMode>,
+) {
+    if let CameraMode::Orbit { distance, .. } = &mut *mode {
+        let dt = time.delta_secs();
+        if keys.pressed(key_bindings.move_ascend) {
+            *distance -= 10. * dt;
+        }
+        if keys.pressed(key_bindings.move_descend) {
+            *distance += 10. * dt;
+        }
+        for ev in scroll_evr.read() {
+            *distance -= ev.y;
+        }
+        *distance = distance.max(0.5);
+    }
+}
+
+/// In `Follow` mode, tracks the followed entity's `Transform` with a fixed offset
+fn apply_camera_follow(
+    mode: Res<CameraMode>,
+    targets: Query<&Transform, Without<FlyCam>>,
+    mut query: Query<&mut Transform, With<FlyCam>>,
+) {
+    if let CameraMode::Follow { entity, offset } = *mode {
+        if let Ok(target_transform) = targets.get(entity) {
+            for mut transform in query.iter_mut() {
+                transform.translation = target_transform.translation + offset;
+                transform.look_at(target_transform.translation, Vec3::Y);
+            }
+        }
+    }
+}
+
+/// Eases the `FlyCam`'s fov toward `ZoomSettings::zoomed_fov` while `KeyBindings::zoom` is held,
+/// and back to `default_fov` on release. Only touches `fov`/`mouse_sensitivity` while the key is
+/// held or the fov is still easing back from a previous hold, so it neither fights a fov set by
+/// `scroll_adjust_settings`'s `ScrollTarget::Zoom` nor leaves `mouse_sensitivity` permanently
+/// pinned to a stale baseline once it's done.
+fn zoom_camera(
+    keys: Res<ButtonInput<KeyCode>>,
+    key_bindings: Res<KeyBindings>,
+    time: Res<Time>,
+    zoom: Res<ZoomSettings>,
+    mut settings: ResMut<MovementSettings>,
+    mut base_sensitivity: Local<Option<f32>>,
+    mut easing_back: Local<bool>,
+    mut query: Query<&mut Projection, With<FlyCam>>,
+) {
+    let held = keys.pressed(key_bindings.zoom);
+
+    if keys.just_pressed(key_bindings.zoom) {
+        *base_sensitivity = Some(settings.mouse_sensitivity);
+        *easing_back = false;
+    }
+    if keys.just_released(key_bindings.zoom) {
+        *easing_back = true;
+    }
+
+    if !held && !*easing_back {
+        return;
+    }
+
+    let default_fov = zoom.default_fov.to_radians();
+    let target_fov = if held { zoom.zoomed_fov } else { zoom.default_fov }.to_radians();
+    let dt = time.delta_secs();
+    let mut settled = true;
+
+    for mut projection in query.iter_mut() {
+        if let Projection::Perspective(perspective) = &mut *projection {
+            perspective.fov += (target_fov - perspective.fov) * (zoom.zoom_speed * dt).min(1.);
+
+            if (perspective.fov - default_fov).abs() > 0.0005 {
+                settled = false;
+            }
+
+            if zoom.scale_sensitivity {
+                if let Some(base) = *base_sensitivity {
+                    settings.mouse_sensitivity = base * (perspective.fov / default_fov);
+                }
+            }
+        }
+    }
+
+    if !held && settled {
+        *easing_back = false;
+        *base_sensitivity = None;
+    }
+}
+
+/// Adds every non-`FlyCam` `Camera3d` that appears in the world (such as one spawned from a
+/// loaded glTF scene) to [`SceneCameras`] so it can be cycled to. Deactivated on discovery so it
+/// doesn't render alongside the `FlyCam` until the user actually cycles to it.
+#[cfg(feature = "scene-camera-cycling")]
+fn discover_scene_cameras(
+    mut scene_cameras: ResMut<SceneCameras>,
+    mut added: Query<(Entity, &mut Camera), (Added<Camera3d>, Without<FlyCam>)>,
+) {
+    for (entity, mut camera) in added.iter_mut() {
+        camera.is_active = false;
+        scene_cameras.cameras.push(entity);
+    }
+}
+
+/// Cycles through `SceneCameras` on a key press, toggling `Camera::is_active` so only one camera
+/// renders at a time. Wrapping back to the `FlyCam` copies the last scene camera's `Transform`
+/// and `Projection` onto it, so flying continues from that authored viewpoint.
+#[cfg(feature = "scene-camera-cycling")]
+fn cycle_scene_cameras(
+    keys: Res<ButtonInput<KeyCode>>,
+    key_bindings: Res<KeyBindings>,
+    mut scene_cameras: ResMut<SceneCameras>,
+    mut flycam_query: Query<(&mut Camera, &mut Transform, &mut Projection), With<FlyCam>>,
+    mut scene_query: Query<(&mut Camera, &Transform, &Projection), Without<FlyCam>>,
+) {
+    if !keys.just_pressed(key_bindings.cycle_scene_camera) || scene_cameras.cameras.is_empty() {
+        return;
+    }
+
+    let Ok((mut flycam, mut flycam_transform, mut flycam_projection)) = flycam_query.single_mut()
+    else {
+        return;
+    };
+
+    let next = match scene_cameras.active {
+        None => Some(0),
+        Some(i) if i + 1 < scene_cameras.cameras.len() => Some(i + 1),
+        Some(_) => None,
+    };
+
+    match next {
+        Some(i) => {
+            if let Some(prev) = scene_cameras.active {
+                if let Ok((mut camera, ..)) = scene_query.get_mut(scene_cameras.cameras[prev]) {
+                    camera.is_active = false;
+                }
+            }
+            if let Ok((mut camera, ..)) = scene_query.get_mut(scene_cameras.cameras[i]) {
+                camera.is_active = true;
+                flycam.is_active = false;
+            }
+        }
+        None => {
+            if let Some(prev) = scene_cameras.active {
+                if let Ok((mut camera, transform, projection)) =
+                    scene_query.get_mut(scene_cameras.cameras[prev])
+                {
+                    camera.is_active = false;
+                    *flycam_transform = *transform;
+                    *flycam_projection = projection.clone();
+                }
+            }
+            flycam.is_active = true;
+        }
+    }
+
+    scene_cameras.active = next;
+}
+
+/// Cycles [`ScrollTarget`] on a key press
+fn cycle_scroll_target(
+    keys: Res<ButtonInput<KeyCode>>,
+    key_bindings: Res<KeyBindings>,
+    mut target: ResMut<ScrollTarget>,
+) {
+    if keys.just_pressed(key_bindings.cycle_scroll_target) {
+        *target = match *target {
+            ScrollTarget::MovementSpeed => ScrollTarget::Sensitivity,
+            ScrollTarget::Sensitivity => ScrollTarget::Zoom,
+            ScrollTarget::Zoom => ScrollTarget::MovementSpeed,
+        };
+    }
+}
+
+/// Adjusts whichever `ScrollTarget` is active using the mouse wheel, so the fly camera's feel
+/// can be tuned in-scene instead of only at compile time
+fn scroll_adjust_settings(
+    mut scroll_evr: EventReader<MouseWheel>,
+    target: Res<ScrollTarget>,
+    mode: Res<CameraMode>,
+    mut settings: ResMut<MovementSettings>,
+    mut query: Query<&mut Projection, With<FlyCam>>,
+) {
+    let scroll: f32 = scroll_evr.read().map(|ev| ev.y).sum();
+
+    // In `Orbit` mode the wheel already drives `adjust_orbit_distance`; don't also retune
+    // movement/sensitivity/zoom settings out from under the user.
+    if scroll == 0. || matches!(*mode, CameraMode::Orbit { .. }) {
+        return;
+    }
+
+    match *target {
+        ScrollTarget::MovementSpeed => {
+            settings.walk_speed = (settings.walk_speed + scroll).clamp(1., 100.);
+            settings.run_speed = (settings.run_speed + scroll * 2.).clamp(1., 200.);
+        }
+        ScrollTarget::Sensitivity => {
+            settings.mouse_sensitivity =
+                (settings.mouse_sensitivity + scroll * 0.00001).clamp(0.00001, 0.001);
+        }
+        ScrollTarget::Zoom => {
+            for mut projection in query.iter_mut() {
+                if let Projection::Perspective(perspective) = &mut *projection {
+                    perspective.fov = (perspective.fov - scroll.to_radians()).clamp(0.1, 2.0);
+                }
+            }
+        }
+    }
+}
+
+/// Toggles the cursor grab on `KeyBindings::toggle_grab_cursor`, or, when
+/// `CursorGrabSettings::hold_to_look` is set, grabs/releases it on that mouse button's up/down
 fn cursor_grab(
     keys: Res<ButtonInput<KeyCode>>,
+    mouse_buttons: Res<ButtonInput<MouseButton>>,
     key_bindings: Res<KeyBindings>,
+    settings: Res<CursorGrabSettings>,
     mut primary_window: Query<&mut Window, With<PrimaryWindow>>,
 ) {
     if let Ok(mut window) = primary_window.single_mut() {
-        if keys.just_pressed(key_bindings.toggle_grab_cursor) {
-            toggle_grab_cursor(&mut window);
+        match settings.hold_to_look {
+            Some(button) => {
+                let grabbed = window.cursor_options.grab_mode != CursorGrabMode::None;
+                if mouse_buttons.just_pressed(button) && !grabbed {
+                    toggle_grab_cursor(&mut window, &settings);
+                } else if mouse_buttons.just_released(button) && grabbed {
+                    toggle_grab_cursor(&mut window, &settings);
+                }
+            }
+            None => {
+                if keys.just_pressed(key_bindings.toggle_grab_cursor) {
+                    toggle_grab_cursor(&mut window, &settings);
+                }
+            }
         }
     } else {
         warn!("Primary window not found for `cursor_grab`!");
     }
 }
 
+/// Applies `CursorGrabSettings::custom_cursor` to the window while the cursor is ungrabbed
+fn apply_custom_cursor(
+    mut commands: Commands,
+    settings: Res<CursorGrabSettings>,
+    primary_window: Query<(Entity, &Window), With<PrimaryWindow>>,
+) {
+    let Some(handle) = &settings.custom_cursor else {
+        return;
+    };
+
+    if let Ok((entity, window)) = primary_window.single() {
+        if window.cursor_options.grab_mode == CursorGrabMode::None {
+            commands
+                .entity(entity)
+                .insert(CursorIcon::Custom(CustomCursor::Image {
+                    handle: handle.clone(),
+                    hotspot: (0, 0),
+                }));
+        }
+    }
+}
+
 #[cfg(feature = "initial-grab-control")]
 // Grab cursor when an entity with FlyCam is added
 fn initial_grab_on_flycam_spawn(
     mut primary_window: Query<&mut Window, With<PrimaryWindow>>,
     query_added: Query<Entity, Added<FlyCam>>,
+    settings: Res<CursorGrabSettings>,
 ) {
     if query_added.is_empty() {
         return;
     }
 
     if let Ok(window) = &mut primary_window.get_single_mut() {
-        toggle_grab_cursor(window);
+        toggle_grab_cursor(window, &settings);
     } else {
         warn!("Primary window not found for `initial_grab_cursor`!");
     }
@@ -232,13 +690,30 @@ impl Plugin for PlayerPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<MovementSettings>()
             .init_resource::<KeyBindings>()
+            .init_resource::<CameraMode>()
+            .init_resource::<ScrollTarget>()
+            .init_resource::<CursorGrabSettings>()
+            .init_resource::<ZoomSettings>()
             .add_systems(Startup, setup_player)
+            .add_systems(Update, initial_velocity_on_flycam_spawn)
+            .add_systems(Update, cycle_camera_mode)
             .add_systems(Update, player_move)
             .add_systems(Update, player_look)
-            .add_systems(Update, cursor_grab);
+            .add_systems(Update, adjust_orbit_distance)
+            .add_systems(Update, apply_camera_follow)
+            .add_systems(Update, cycle_scroll_target)
+            .add_systems(Update, scroll_adjust_settings)
+            .add_systems(Update, zoom_camera)
+            .add_systems(Update, cursor_grab)
+            .add_systems(Update, apply_custom_cursor);
 
         #[cfg(feature = "initial-grab-control")]
         app.add_systems(Startup, initial_grab_cursor);
+
+        #[cfg(feature = "scene-camera-cycling")]
+        app.init_resource::<SceneCameras>()
+            .add_systems(Update, discover_scene_cameras)
+            .add_systems(Update, cycle_scene_cameras);
     }
 }
 
@@ -248,14 +723,31 @@ impl Plugin for NoCameraPlayerPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<MovementSettings>()
             .init_resource::<KeyBindings>()
+            .init_resource::<CameraMode>()
+            .init_resource::<ScrollTarget>()
+            .init_resource::<CursorGrabSettings>()
+            .init_resource::<ZoomSettings>()
+            .add_systems(Update, initial_velocity_on_flycam_spawn)
+            .add_systems(Update, cycle_camera_mode)
             .add_systems(Update, player_move)
             .add_systems(Update, player_look)
-            .add_systems(Update, cursor_grab);
+            .add_systems(Update, adjust_orbit_distance)
+            .add_systems(Update, apply_camera_follow)
+            .add_systems(Update, cycle_scroll_target)
+            .add_systems(Update, scroll_adjust_settings)
+            .add_systems(Update, zoom_camera)
+            .add_systems(Update, cursor_grab)
+            .add_systems(Update, apply_custom_cursor);
 
         #[cfg(feature = "initial-grab-control")]
         {
             app.add_systems(Startup, initial_grab_cursor);
             app.add_systems(Startup, initial_grab_on_flycam_spawn);
         }
+
+        #[cfg(feature = "scene-camera-cycling")]
+        app.init_resource::<SceneCameras>()
+            .add_systems(Update, discover_scene_cameras)
+            .add_systems(Update, cycle_scene_cameras);
     }
 }